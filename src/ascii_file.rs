@@ -1,17 +1,28 @@
 use std::{
     collections::HashMap,
-    io::{BufRead, BufReader, Error, Lines, Read, Seek, SeekFrom},
+    fs::File,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{BufRead, BufReader, Cursor, Error, Lines, Read, Seek, SeekFrom, Write},
+    path::Path,
     vec::IntoIter,
 };
 
-use crate::header::EsriASCIIRasterHeader;
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+
+use crate::{builder::ParseErrorPolicy, error::GridError, header::EsriASCIIRasterHeader};
 
 pub struct EsriASCIIReader<R> {
     pub header: EsriASCIIRasterHeader,
-    reader: BufReader<R>,
-    line_cache: HashMap<usize, Vec<f64>>,
-    line_start_cache: Vec<u64>,
-    data_start: u64,
+    pub(crate) reader: BufReader<R>,
+    pub(crate) line_cache: HashMap<usize, Vec<f64>>,
+    pub(crate) line_start_cache: Vec<u64>,
+    pub(crate) data_start: u64,
+    /// Custom field delimiter. `None` means split on any run of whitespace, matching the
+    /// standard ESRI ASCII grid layout. Set via [`EsriASCIIReaderBuilder`](crate::builder::EsriASCIIReaderBuilder).
+    pub(crate) delimiter: Option<char>,
+    /// What to do when a cell's token fails to parse as an `f64`.
+    pub(crate) on_parse_error: ParseErrorPolicy,
 }
 impl<R: Read + Seek> EsriASCIIReader<R> {
     /// Create a new `EsriASCIIReader` from a file.
@@ -27,8 +38,8 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     /// // This will build the index and cache the file positions of each line, it will take a while for large files but will drastically increase subsequent get calls
     /// grid.build_index().unwrap();
     /// // Spot check a few values
-    /// assert_eq!(grid.get(390000.0, 344000.0).unwrap(), 141.2700042724609375);
-    /// assert_eq!(grid.get(390003.0, 344003.0).unwrap(), 135.44000244140625);
+    /// assert_eq!(grid.get(390000.0, 344000.0).unwrap(), Some(Some(141.2700042724609375)));
+    /// assert_eq!(grid.get(390003.0, 344003.0).unwrap(), Some(Some(135.44000244140625)));
     /// ```
     /// # Errors
     /// Returns an IO error if there is someghing wrong with the header, such as missing values
@@ -43,6 +54,8 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
             line_cache: HashMap::new(),
             line_start_cache: Vec::new(),
             data_start,
+            delimiter: None,
+            on_parse_error: ParseErrorPolicy::Error,
         })
     }
     /// Build an index of the file.
@@ -69,8 +82,88 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
         };
         Ok(())
     }
+    /// Saves the line index built by [`build_index`](Self::build_index) to `path`, alongside a
+    /// fingerprint of the underlying file so [`load_index`](Self::load_index) can tell whether
+    /// the cache is still valid before trusting it.
+    ///
+    /// # Errors
+    /// Returns an IO error if `path` cannot be written, or if computing the fingerprint fails.
+    pub fn save_index(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let fingerprint = self.index_fingerprint()?;
+        let mut file = std::io::BufWriter::new(File::create(path)?);
+        file.write_all(&fingerprint.to_le_bytes())?;
+        file.write_all(&self.data_start.to_le_bytes())?;
+        file.write_all(&(self.line_start_cache.len() as u64).to_le_bytes())?;
+        for offset in &self.line_start_cache {
+            file.write_all(&offset.to_le_bytes())?;
+        }
+        file.flush()
+    }
+    /// Loads a line index previously saved with [`save_index`](Self::save_index) from `path`.
+    ///
+    /// The stored fingerprint (file length plus a hash of the header and a few sampled data
+    /// offsets) is recomputed and compared; the cached offsets are only adopted if it matches.
+    /// Returns `Ok(true)` if the index was loaded, `Ok(false)` if the fingerprint didn't match
+    /// and the caller should fall back to [`build_index`](Self::build_index).
+    ///
+    /// # Errors
+    /// Returns an IO error if `path` cannot be read or is truncated.
+    pub fn load_index(&mut self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        let mut file = File::open(path)?;
+        let mut buf = [0u8; 8];
+        file.read_exact(&mut buf)?;
+        let stored_fingerprint = u64::from_le_bytes(buf);
+        if stored_fingerprint != self.index_fingerprint()? {
+            return Ok(false);
+        }
+        file.read_exact(&mut buf)?;
+        let data_start = u64::from_le_bytes(buf);
+        file.read_exact(&mut buf)?;
+        let len = u64::from_le_bytes(buf) as usize;
+        let mut line_start_cache = Vec::with_capacity(len);
+        for _ in 0..len {
+            file.read_exact(&mut buf)?;
+            line_start_cache.push(u64::from_le_bytes(buf));
+        }
+        self.data_start = data_start;
+        self.line_start_cache = line_start_cache;
+        Ok(true)
+    }
+    /// Computes a cheap content fingerprint: the file length, the header fields, and a handful
+    /// of bytes sampled at evenly spaced offsets through the data region.
+    fn index_fingerprint(&mut self) -> Result<u64, Error> {
+        let mut hasher = DefaultHasher::new();
+        let reader = self.reader.by_ref();
+        let original_position = reader.stream_position()?;
+        let file_length = reader.seek(SeekFrom::End(0))?;
+
+        file_length.hash(&mut hasher);
+        self.header.ncols.hash(&mut hasher);
+        self.header.nrows.hash(&mut hasher);
+        self.header.xllcorner.to_bits().hash(&mut hasher);
+        self.header.yllcorner.to_bits().hash(&mut hasher);
+        self.header.cellsize.to_bits().hash(&mut hasher);
+        self.header.nodata_value.to_bits().hash(&mut hasher);
+
+        const SAMPLE_POINTS: u64 = 8;
+        let data_len = file_length.saturating_sub(self.data_start);
+        let reader = self.reader.by_ref();
+        for i in 0..SAMPLE_POINTS {
+            let offset = self.data_start + data_len * i / SAMPLE_POINTS;
+            reader.seek(SeekFrom::Start(offset))?;
+            let mut byte = [0u8; 1];
+            if reader.read(&mut byte)? > 0 {
+                byte[0].hash(&mut hasher);
+            }
+        }
+        self.reader.by_ref().seek(SeekFrom::Start(original_position))?;
+        Ok(hasher.finish())
+    }
     /// Returns the value at the given row and column.
     /// 0, 0 is the bottom left corner. The row and column are zero indexed.
+    ///
+    /// Returns `Ok(None)` if the cell holds the header's `NODATA_value`.
+    ///
     /// # Examples
     /// ```rust
     /// use esri_ascii_grid::ascii_file::EsriASCIIReader;
@@ -80,41 +173,67 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     /// // This will build the index and cache the file positions of each line, it will take a while for large files but will drastically increase subsequent get calls
     /// grid.build_index().unwrap();
     /// // Spot check a few values
-    /// assert_eq!(grid.get_index(0, 0).unwrap(), 141.270_004_272_460_937_5);
-    /// assert_eq!(grid.get_index(3, 3).unwrap(), 135.440_002_441_406_25);
+    /// assert_eq!(grid.get_index(0, 0).unwrap(), Some(141.270_004_272_460_937_5));
+    /// assert_eq!(grid.get_index(3, 3).unwrap(), Some(135.440_002_441_406_25));
     /// ```
     ///
     /// # Errors
-    /// Returns an IO error if the row or column is out of bounds or is not a valid number.
-    ///
-    /// # Panics
-    /// Panics if the row or column is out of bounds, which should not happen as they are checked in this function.
-    pub fn get_index(&mut self, row: usize, col: usize) -> Result<f64, Error> {
+    /// Returns an error if the row or column is out of bounds, an IO operation fails, or a cell
+    /// is not a valid number (subject to the reader's [`ParseErrorPolicy`]).
+    pub fn get_index(&mut self, row: usize, col: usize) -> Result<Option<f64>, GridError> {
         if row >= self.header.nrows || col >= self.header.ncols {
-            return Err(Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Index out of bounds",
-            ));
+            return Err(GridError::OutOfBounds {
+                row,
+                col,
+                nrows: self.header.nrows,
+                ncols: self.header.ncols,
+            });
         };
         if let Some(values) = self.line_cache.get(&row) {
-            let val = values[col];
-            return Ok(val);
+            let val = *values.get(col).ok_or(GridError::ShortRow {
+                row,
+                expected_cols: self.header.ncols,
+            })?;
+            return Ok(self.value_or_nodata(val));
         }
         let reader = self.reader.by_ref();
         let line = if self.line_start_cache.is_empty() {
             reader.seek(SeekFrom::Start(self.data_start))?;
-            reader.lines().nth(self.header.nrows - 1 - row).unwrap()?
+            reader
+                .lines()
+                .nth(self.header.nrows - 1 - row)
+                .ok_or(GridError::UnexpectedEof { row })??
         } else {
             let line_start = self.line_start_cache[row];
             reader.seek(SeekFrom::Start(line_start))?;
-            reader.lines().next().unwrap()?
+            reader
+                .lines()
+                .next()
+                .ok_or(GridError::UnexpectedEof { row })??
         };
-        let values: Vec<f64> = line
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
-        self.line_cache.insert(row, values.clone());
-        Ok(values[col])
+        let raw_fields: Vec<&str> = match self.delimiter {
+            Some(delimiter) => line.split(delimiter).filter(|s| !s.is_empty()).collect(),
+            None => line.split_whitespace().collect(),
+        };
+        let values: Vec<f64> = raw_fields
+            .into_iter()
+            .enumerate()
+            .map(|(col, token)| parse_cell(token, self.on_parse_error, self.header.nodata_value, row, col))
+            .collect::<Result<_, _>>()?;
+        let val = *values.get(col).ok_or(GridError::ShortRow {
+            row,
+            expected_cols: self.header.ncols,
+        })?;
+        self.line_cache.insert(row, values);
+        Ok(self.value_or_nodata(val))
+    }
+    /// Maps a raw cell value to `None` if it equals the header's `NODATA_value`.
+    fn value_or_nodata(&self, val: f64) -> Option<f64> {
+        if val == self.header.nodata_value {
+            None
+        } else {
+            Some(val)
+        }
     }
     /// Returns the value at the given x and y coordinates.
     ///
@@ -123,6 +242,9 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     ///
     /// If the coordinates are within the bounds of the raster, but not on a cell, the value of the nearest cell is returned
     ///
+    /// The outer `Option` is `None` if the coordinates are outside the bounds of the raster;
+    /// the inner `Option` is `None` if the nearest cell is `NODATA`.
+    ///
     /// # Examples
     /// ```rust
     /// use esri_ascii_grid::ascii_file::EsriASCIIReader;
@@ -132,16 +254,18 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     /// // This will build the index and cache the file positions of each line, it will take a while for large files but will drastically increase subsequent get calls
     /// grid.build_index().unwrap();
     /// // Spot check a few values
-    /// assert_eq!(grid.get(390000.0, 344000.0).unwrap(), 141.2700042724609375);
-    /// assert_eq!(grid.get(390003.0, 344003.0).unwrap(), 135.44000244140625);
+    /// assert_eq!(grid.get(390000.0, 344000.0).unwrap(), Some(Some(141.2700042724609375)));
+    /// assert_eq!(grid.get(390003.0, 344003.0).unwrap(), Some(Some(135.44000244140625)));
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the coordinates are outside the bounds of the raster, which should not happen as they are checked in this function.
-    pub fn get(&mut self, x: f64, y: f64) -> Option<f64> {
-        let (col, row) = self.header.index_of(x, y)?;
-        let val = self.get_index(row, col).unwrap();
-        Some(val)
+    ///
+    /// # Errors
+    /// Returns an error if an IO operation fails or a cell is not a valid number (subject to the
+    /// reader's [`ParseErrorPolicy`]).
+    pub fn get(&mut self, x: f64, y: f64) -> Result<Option<Option<f64>>, GridError> {
+        let Some((col, row)) = self.header.index_of(x, y) else {
+            return Ok(None);
+        };
+        Ok(Some(self.get_index(row, col)?))
     }
     /// Returns the value at the given x and y coordinates.
     ///
@@ -150,7 +274,12 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     /// The value is interpolated from the four nearest cells.
     ///
     /// Even if the coordinates are exactly on a cell, the value is interpolated and so may or may not be the same as the value at the cell due to floating point errors.
-    /// 
+    ///
+    /// If one or more of the four surrounding corners is `NODATA`, the bilinear weights are
+    /// renormalized over the remaining valid corners. If the valid corners carry no weight (all
+    /// four corners are `NODATA`, or the point lies exactly on `NODATA` corners only), or the
+    /// coordinates are outside the bounds of the raster, `None` is returned.
+    ///
     /// # Examples
     /// ```rust
     /// use esri_ascii_grid::ascii_file::EsriASCIIReader;
@@ -160,31 +289,42 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
     /// // This will build the index and cache the file positions of each line, it will take a while for large files but will drastically increase subsequent get calls
     /// grid.build_index().unwrap();
     /// // Spot check a few values
-    /// assert_eq!(grid.get_interpolate(390000.0, 344000.0).unwrap(), 141.2700042724609375);
-    /// assert_eq!(grid.get_interpolate(390003.0, 344003.0).unwrap(), 135.44000244140625);
+    /// assert_eq!(grid.get_interpolate(390000.0, 344000.0).unwrap(), Some(141.2700042724609375));
+    /// assert_eq!(grid.get_interpolate(390003.0, 344003.0).unwrap(), Some(135.44000244140625));
     /// ```
-    /// 
-    /// # Panics
-    /// Panics if the coordinates are outside the bounds of the raster, which should not happen as they are checked in this function.
-    pub fn get_interpolate(&mut self, x: f64, y: f64) -> Option<f64> {
+    ///
+    /// # Errors
+    /// Returns an error if an IO operation fails or a cell is not a valid number (subject to the
+    /// reader's [`ParseErrorPolicy`]).
+    pub fn get_interpolate(&mut self, x: f64, y: f64) -> Result<Option<f64>, GridError> {
         if x < self.header.min_x()
             || x > self.header.max_x()
             || y < self.header.min_y()
             || y > self.header.max_y()
         {
-            return None;
+            return Ok(None);
+        }
+        // Bilinear interpolation needs four distinct corners; a 1-row or 1-column grid can't
+        // provide them, and `ncols - 2`/`nrows - 2` below would underflow.
+        if self.header.ncols < 2 || self.header.nrows < 2 {
+            return Ok(None);
         }
         let ll_col = (((x - self.header.min_x()) / self.header.cellsize).floor() as usize)
             .min(self.header.ncols - 2);
         let ll_row = (((y - self.header.min_y()) / self.header.cellsize).floor() as usize)
             .min(self.header.nrows - 2);
 
-        let (ll_x, ll_y) = self.header.index_pos(ll_row, ll_col).unwrap();
+        let (ll_x, ll_y) = self.header.index_pos(ll_row, ll_col).ok_or(GridError::OutOfBounds {
+            row: ll_row,
+            col: ll_col,
+            nrows: self.header.nrows,
+            ncols: self.header.ncols,
+        })?;
 
-        let ll = self.get_index(ll_row, ll_col).unwrap();
-        let lr = self.get_index(ll_row, ll_col + 1).unwrap();
-        let ul = self.get_index(ll_row + 1, ll_col).unwrap();
-        let ur = self.get_index(ll_row + 1, ll_col + 1).unwrap();
+        let ll = self.get_index(ll_row, ll_col)?;
+        let lr = self.get_index(ll_row, ll_col + 1)?;
+        let ul = self.get_index(ll_row + 1, ll_col)?;
+        let ur = self.get_index(ll_row + 1, ll_col + 1)?;
 
         let vert_weight = (x - ll_x) / self.header.cell_size();
         let horiz_weight = (y - ll_y) / self.header.cell_size();
@@ -194,18 +334,131 @@ impl<R: Read + Seek> EsriASCIIReader<R> {
         let ul_weight = (1.0 - vert_weight) * horiz_weight;
         let lr_weight = vert_weight * (1.0 - horiz_weight);
 
-        let value = ul * ul_weight + ur * ur_weight + ll * ll_weight + lr * lr_weight;
-        Some(value)
+        let corners = [(ll, ll_weight), (lr, lr_weight), (ul, ul_weight), (ur, ur_weight)];
+        let valid_weight: f64 = corners
+            .iter()
+            .filter_map(|(val, weight)| val.map(|_| weight))
+            .sum();
+        if valid_weight == 0.0 {
+            return Ok(None);
+        }
+        let value = corners
+            .iter()
+            .filter_map(|(val, weight)| val.map(|v| v * weight))
+            .sum::<f64>()
+            / valid_weight;
+        Ok(Some(value))
+    }
+}
+#[cfg(feature = "mmap")]
+impl EsriASCIIReader<Cursor<Mmap>> {
+    /// Create a new `EsriASCIIReader` by memory-mapping `file` instead of reading it through a
+    /// `BufReader`.
+    ///
+    /// This avoids the per-line allocations of [`build_index`](Self::build_index) on large
+    /// grids: after the header is parsed, the full `line_start_cache` is populated by a single
+    /// incremental scan over the mapped bytes, so there is no separate indexing pass to run.
+    ///
+    /// Requires the `mmap` feature.
+    ///
+    /// # Errors
+    /// Returns an IO error if the header is malformed or the file ends before `nrows` rows are found.
+    pub fn from_mmap(file: std::fs::File) -> Result<Self, Error> {
+        // Safety: the caller must ensure `file` is not concurrently truncated or modified while
+        // it remains mapped, as required by `memmap2::Mmap::map`.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let mut header_cursor = Cursor::new(&mmap[..]);
+        let grid_header = EsriASCIIRasterHeader::from_reader(&mut header_cursor)?;
+        let data_start = header_cursor.position();
+
+        let line_start_cache = scan_line_starts(&mmap, data_start, grid_header.num_rows())?;
+
+        Ok(Self {
+            header: grid_header,
+            reader: BufReader::new(Cursor::new(mmap)),
+            line_cache: HashMap::new(),
+            line_start_cache,
+            data_start,
+            delimiter: None,
+            on_parse_error: ParseErrorPolicy::Error,
+        })
     }
 }
+/// Parses a single cell token as an `f64`, applying `on_parse_error` if it fails to parse.
+fn parse_cell(
+    token: &str,
+    on_parse_error: ParseErrorPolicy,
+    nodata_value: f64,
+    row: usize,
+    col: usize,
+) -> Result<f64, GridError> {
+    token.parse().or_else(|_| match on_parse_error {
+        ParseErrorPolicy::Error => Err(GridError::ParseCell {
+            token: token.to_string(),
+            row,
+            col,
+        }),
+        ParseErrorPolicy::Nodata => Ok(nodata_value),
+    })
+}
+#[cfg(feature = "mmap")]
+/// Walks `bytes[data_start..]` with an incremental `csv-core` field scanner, recording the
+/// absolute byte offset of the start of every record (row) as it goes.
+fn scan_line_starts(bytes: &[u8], data_start: u64, num_rows: usize) -> Result<Vec<u64>, Error> {
+    let mut field_reader = csv_core::ReaderBuilder::new().delimiter(b' ').build();
+    let mut scratch = [0u8; 64];
+    let mut pos = data_start as usize;
+    let mut row_start = pos;
+    let mut line_starts = Vec::with_capacity(num_rows);
+    while line_starts.len() < num_rows {
+        let (result, bytes_read, _bytes_written) =
+            field_reader.read_field(&bytes[pos..], &mut scratch);
+        pos += bytes_read;
+        match result {
+            csv_core::ReadFieldResult::Field { record_end } => {
+                if record_end {
+                    line_starts.push(row_start as u64);
+                    row_start = pos;
+                }
+            }
+            csv_core::ReadFieldResult::End | csv_core::ReadFieldResult::InputEmpty => {
+                // A missing trailing newline on the last row is common in real `.asc` files;
+                // treat end-of-input as an implicit terminator for a pending final record
+                // instead of silently dropping it, mirroring `BufRead::lines()`'s tolerance of
+                // a missing trailing newline in the non-mmap `build_index` path.
+                if pos > row_start && line_starts.len() + 1 == num_rows {
+                    line_starts.push(row_start as u64);
+                }
+                break;
+            }
+            csv_core::ReadFieldResult::OutputFull => {}
+        }
+    }
+    if line_starts.len() != num_rows {
+        return Err(Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "Unexpected end of file",
+        ));
+    }
+    line_starts.reverse();
+    Ok(line_starts)
+}
 impl<R: Read + Seek> IntoIterator for EsriASCIIReader<R> {
-    type Item = (usize, usize, f64);
+    type Item = Result<(usize, usize, Option<f64>), GridError>;
     type IntoIter = EsriASCIIRasterIntoIterator<R>;
-    /// Returns an iterator over the values in the raster.
+    /// Returns a fallible iterator over the values in the raster.
     /// The iterator will scan the raster from left to right, top to bottom.
     /// So the row will start at num_rows-1 and decrease to 0.
     /// The column will start at 0 and increase to num_cols-1.
     ///
+    /// The value is `None` where the cell holds the header's `NODATA_value`. An `Err` is
+    /// yielded, and iteration stops making progress, if an IO operation fails or a cell is not
+    /// a valid number.
+    ///
+    /// The iterator always splits cells on whitespace and treats an unparseable token as an
+    /// error; a delimiter or [`ParseErrorPolicy`] set on the reader is not applied here.
+    ///
     /// ```rust
     /// let file = std::fs::File::open("test_data/test.asc").unwrap();
     /// let grid = esri_ascii_grid::ascii_file::EsriASCIIReader::from_file(file).unwrap();
@@ -213,43 +466,42 @@ impl<R: Read + Seek> IntoIterator for EsriASCIIReader<R> {
     /// let header = grid.header;
     /// let iter = grid.into_iter();
     /// let mut num_elements = 0;
-    /// for (row, col, value) in iter {
+    /// for item in iter {
+    ///     let (row, col, value) = item.unwrap();
     ///     num_elements += 1;
     ///     if row == 3 && col == 3 {
     ///         let (x, y) = header.index_pos(col, row).unwrap();
     ///         assert_eq!(x, 390003.0);
     ///         assert_eq!(y, 344003.0);
-    ///         assert_eq!(value, 135.44000244140625);
+    ///         assert_eq!(value, Some(135.44000244140625));
     ///     }
     ///     if row == 0 && col == 0 {
     ///         let (x, y) = header.index_pos(col, row).unwrap();
     ///         assert_eq!(x, 390000.0);
     ///         assert_eq!(y, 344000.0);
-    ///         assert_eq!(value, 141.2700042724609375);
+    ///         assert_eq!(value, Some(141.2700042724609375));
     ///     }
     /// }
     /// assert_eq!(grid_size, num_elements);
     /// ```
     ///
     fn into_iter(self) -> Self::IntoIter {
+        let ncols = self.header.ncols;
         let mut reader = self.reader;
-        reader.rewind().unwrap();
-        reader
-            .seek(std::io::SeekFrom::Start(self.data_start))
-            .unwrap();
-        let mut lines = reader.lines();
-        let line_string = lines.next().unwrap().unwrap();
-        let line = line_string
-            .split_whitespace()
-            .map(|s| s.parse::<f64>().unwrap())
-            .collect::<Vec<f64>>()
-            .into_iter();
+        let init_error = reader
+            .rewind()
+            .and_then(|()| reader.seek(SeekFrom::Start(self.data_start)).map(|_| ()))
+            .err()
+            .map(GridError::from);
         EsriASCIIRasterIntoIterator {
             header: self.header,
-            lines,
-            line,
-            row: 0,
-            col: 0,
+            lines: reader.lines(),
+            line: Vec::new().into_iter(),
+            // `next` advances the row before loading a line, so this wraps to row 0 on the
+            // first call; `col: ncols` forces that first call to load row 0 before yielding.
+            row: usize::MAX,
+            col: ncols,
+            pending_error: init_error,
         }
     }
 }
@@ -260,28 +512,68 @@ pub struct EsriASCIIRasterIntoIterator<R> {
     line: IntoIter<f64>,
     row: usize,
     col: usize,
+    pending_error: Option<GridError>,
 }
 impl<R: Read + Seek> Iterator for EsriASCIIRasterIntoIterator<R> {
-    type Item = (usize, usize, f64);
+    type Item = Result<(usize, usize, Option<f64>), GridError>;
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.pending_error.take() {
+            // The seek in `into_iter` failed; that error is surfaced exactly once, then
+            // iteration stops making progress for good.
+            return self.fail(err);
+        }
         if self.col >= self.header.ncols {
-            self.row += 1;
+            self.row = self.row.wrapping_add(1);
             self.col = 0;
             if self.row >= self.header.nrows {
                 return None;
             }
-            let line_string = self.lines.next().unwrap().unwrap();
-            let line = line_string
+            // Errors report the row in the same bottom-up numbering as the yielded items.
+            let grid_row = self.header.nrows - 1 - self.row;
+            let line_string = match self.lines.next() {
+                Some(Ok(line)) => line,
+                Some(Err(err)) => return self.fail(err.into()),
+                None => return self.fail(GridError::UnexpectedEof { row: grid_row }),
+            };
+            let values: Result<Vec<f64>, GridError> = line_string
                 .split_whitespace()
-                .map(|s| s.parse::<f64>().unwrap())
-                .collect::<Vec<f64>>()
-                .into_iter();
-            self.line = line;
+                .enumerate()
+                .map(|(col, token)| parse_cell(token, ParseErrorPolicy::Error, self.header.nodata_value, grid_row, col))
+                .collect();
+            let values = match values {
+                Ok(values) => values,
+                Err(err) => return self.fail(err),
+            };
+            self.line = values.into_iter();
         }
         let current_col = self.col;
         let current_row = self.row;
         self.col += 1;
-        let value = self.line.next().unwrap();
-        Some((self.header.nrows - 1 - current_row, current_col, value))
+        let value = match self.line.next() {
+            Some(value) => value,
+            None => {
+                return self.fail(GridError::ShortRow {
+                    row: self.header.nrows - 1 - current_row,
+                    expected_cols: self.header.ncols,
+                })
+            }
+        };
+        let value = if value == self.header.nodata_value {
+            None
+        } else {
+            Some(value)
+        };
+        Some(Ok((self.header.nrows - 1 - current_row, current_col, value)))
+    }
+}
+impl<R: Read + Seek> EsriASCIIRasterIntoIterator<R> {
+    /// Stops the iterator from making any further progress: the next call to `next` will return
+    /// `None` instead of resuming on whatever line happens to follow the failure. Returns the
+    /// error wrapped for this call's `Some(Err(..))`.
+    fn fail(&mut self, err: GridError) -> Option<Result<(usize, usize, Option<f64>), GridError>> {
+        self.row = self.header.nrows;
+        self.col = self.header.ncols;
+        self.line = Vec::new().into_iter();
+        Some(Err(err))
     }
 }
@@ -0,0 +1,144 @@
+use std::io::{Error, Read, Seek};
+
+use crate::ascii_file::EsriASCIIReader;
+
+/// What to do when a cell's token fails to parse as an `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorPolicy {
+    /// Return an error from the accessor that hit the unparseable token.
+    Error,
+    /// Treat the token as the grid's `NODATA_value` instead of failing.
+    Nodata,
+}
+
+/// Which corner of a cell the header's `xllcorner`/`yllcorner` describe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginConvention {
+    /// `xllcorner`/`yllcorner` are the lower-left corner of the lower-left cell (the ESRI
+    /// default).
+    Corner,
+    /// `xllcorner`/`yllcorner` are the center of the lower-left cell (the `xllcenter`/`yllcenter`
+    /// dialect). All coordinate math is shifted by half a cell to compensate.
+    Center,
+}
+
+/// Configures and builds an [`EsriASCIIReader`], in the style of a configurable CSV reader.
+///
+/// `from_file` hard-codes whitespace splitting, `xllcorner`-style origins, and trust in the
+/// file's own `NODATA_value`. Use this builder for `.asc` dialects that deviate from that, e.g.
+/// custom delimiters, a missing or wrong `NODATA_value`, or `xllcenter`/`yllcenter` origins.
+///
+/// # Examples
+/// ```rust
+/// use esri_ascii_grid::builder::{EsriASCIIReaderBuilder, OriginConvention};
+/// use std::io::Cursor;
+///
+/// let asc = "ncols 2\nnrows 2\nxllcorner 0\nyllcorner 0\ncellsize 10\nNODATA_value -9999\n3,4\n1,2\n";
+///
+/// // A custom delimiter is actually used to split cells.
+/// let mut grid = EsriASCIIReaderBuilder::new()
+///     .delimiter(',')
+///     .build(Cursor::new(asc))
+///     .unwrap();
+/// assert_eq!(grid.get_index(0, 0).unwrap(), Some(1.0));
+/// assert_eq!(grid.get_index(1, 1).unwrap(), Some(4.0));
+///
+/// // An `xllcenter`/`yllcenter`-style origin shifts the grid's extent by half a cell, moving
+/// // which cell a given coordinate falls into.
+/// let mut corner_grid = EsriASCIIReaderBuilder::new()
+///     .delimiter(',')
+///     .build(Cursor::new(asc))
+///     .unwrap();
+/// assert_eq!(corner_grid.get(-3.0, -3.0).unwrap(), None);
+///
+/// let mut center_grid = EsriASCIIReaderBuilder::new()
+///     .delimiter(',')
+///     .origin(OriginConvention::Center)
+///     .build(Cursor::new(asc))
+///     .unwrap();
+/// assert_eq!(center_grid.get(-3.0, -3.0).unwrap(), Some(Some(1.0)));
+/// ```
+pub struct EsriASCIIReaderBuilder {
+    delimiter: Option<char>,
+    nodata_override: Option<f64>,
+    on_parse_error: ParseErrorPolicy,
+    origin: OriginConvention,
+}
+impl Default for EsriASCIIReaderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl EsriASCIIReaderBuilder {
+    /// Creates a builder with the same defaults as [`EsriASCIIReader::from_file`]: whitespace
+    /// delimiting, the file's own `NODATA_value`, parse errors surfaced as errors, and
+    /// `xllcorner`/`yllcorner`-style origins.
+    pub fn new() -> Self {
+        Self {
+            delimiter: None,
+            nodata_override: None,
+            on_parse_error: ParseErrorPolicy::Error,
+            origin: OriginConvention::Corner,
+        }
+    }
+    /// Sets a custom field delimiter, replacing the default whitespace splitting in
+    /// [`EsriASCIIReader::get_index`] and the accessors built on it. The reader's `IntoIterator`
+    /// still splits on whitespace.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+    /// Overrides the `NODATA_value`, for files that omit it or declare the wrong one.
+    pub fn nodata_value(mut self, value: f64) -> Self {
+        self.nodata_override = Some(value);
+        self
+    }
+    /// Sets what happens when a cell's token fails to parse as an `f64` in
+    /// [`EsriASCIIReader::get_index`] and the accessors built on it. The reader's `IntoIterator`
+    /// always treats such a token as an error.
+    pub fn on_parse_error(mut self, policy: ParseErrorPolicy) -> Self {
+        self.on_parse_error = policy;
+        self
+    }
+    /// Declares whether the header's origin describes a cell corner or a cell center.
+    pub fn origin(mut self, origin: OriginConvention) -> Self {
+        self.origin = origin;
+        self
+    }
+    /// Finalizes the builder into an [`EsriASCIIReader`] over `file`.
+    ///
+    /// # Errors
+    /// Returns an IO error if there is something wrong with the header, such as missing values.
+    pub fn build<R: Read + Seek>(self, file: R) -> Result<EsriASCIIReader<R>, Error> {
+        let mut reader = EsriASCIIReader::from_file(file)?;
+        self.apply(&mut reader);
+        Ok(reader)
+    }
+    /// Finalizes the builder into an [`EsriASCIIReader`] that memory-maps `file`, via
+    /// [`EsriASCIIReader::from_mmap`]. Requires the `mmap` feature.
+    ///
+    /// # Errors
+    /// Returns an IO error if there is something wrong with the header, such as missing values.
+    #[cfg(feature = "mmap")]
+    pub fn build_mmap(
+        self,
+        file: std::fs::File,
+    ) -> Result<EsriASCIIReader<std::io::Cursor<memmap2::Mmap>>, Error> {
+        let mut reader = EsriASCIIReader::from_mmap(file)?;
+        self.apply(&mut reader);
+        Ok(reader)
+    }
+    /// Applies the configured overrides to an already-constructed reader.
+    fn apply<R>(self, reader: &mut EsriASCIIReader<R>) {
+        if let Some(nodata_value) = self.nodata_override {
+            reader.header.nodata_value = nodata_value;
+        }
+        if self.origin == OriginConvention::Center {
+            let half_cell = reader.header.cellsize / 2.0;
+            reader.header.xllcorner -= half_cell;
+            reader.header.yllcorner -= half_cell;
+        }
+        reader.delimiter = self.delimiter;
+        reader.on_parse_error = self.on_parse_error;
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+/// Errors produced while reading an ESRI ASCII grid.
+#[derive(Debug, Error)]
+pub enum GridError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("could not parse cell value {token:?} at row {row}, col {col}")]
+    ParseCell {
+        token: String,
+        row: usize,
+        col: usize,
+    },
+
+    #[error("row {row} has fewer than {expected_cols} columns")]
+    ShortRow { row: usize, expected_cols: usize },
+
+    #[error("index out of bounds: row {row}, col {col} (grid is {nrows} rows x {ncols} cols)")]
+    OutOfBounds {
+        row: usize,
+        col: usize,
+        nrows: usize,
+        ncols: usize,
+    },
+
+    #[error("unexpected end of file while reading row {row}")]
+    UnexpectedEof { row: usize },
+}
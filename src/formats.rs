@@ -0,0 +1,228 @@
+use std::{
+    fs::File,
+    io::{Cursor, Error, ErrorKind, Read},
+    path::Path,
+};
+
+use crate::{ascii_file::EsriASCIIReader, header::EsriASCIIRasterHeader, writer::EsriASCIIWriter};
+
+/// The magic line at the top of a Surfer 6 text grid (`.grd`).
+const SURFER_MAGIC: &str = "DSAA";
+
+/// Surfer's conventional blanking (NODATA) value.
+const SURFER_NODATA_VALUE: f64 = 1.701_41e38;
+
+/// The largest grid [`from_xyz_str`] will allocate. A pair of near-duplicate coordinates can
+/// drive the inferred `cellsize` towards zero, which in turn inflates `ncols * nrows` enough to
+/// abort the process on allocation; this bounds that instead of letting it happen.
+const MAX_XYZ_GRID_CELLS: usize = 64 * 1024 * 1024;
+
+/// A raster source format this crate can read, in addition to the native ESRI ASCII grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridFormat {
+    /// An ESRI ASCII grid (`.asc`).
+    EsriAscii,
+    /// A Surfer 6 text grid (`.grd`), identified by its `DSAA` magic line.
+    SurferAscii,
+    /// A whitespace-separated `x y z` point file.
+    Xyz,
+}
+impl GridFormat {
+    /// Detects the format of `path` by extension, falling back to sniffing `first_token`
+    /// (the first whitespace-separated token in the file) for extension-less or misnamed files.
+    pub fn detect(path: &Path, first_token: &str) -> Self {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase)
+        {
+            Some(ext) if ext == "grd" => GridFormat::SurferAscii,
+            Some(ext) if ext == "xyz" => GridFormat::Xyz,
+            _ if first_token.eq_ignore_ascii_case(SURFER_MAGIC) => GridFormat::SurferAscii,
+            _ => GridFormat::EsriAscii,
+        }
+    }
+}
+
+fn invalid_data(msg: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, msg.to_string())
+}
+fn next_token<'a>(tokens: &mut impl Iterator<Item = &'a str>, what: &str) -> Result<&'a str, Error> {
+    tokens
+        .next()
+        .ok_or_else(|| invalid_data(&format!("unexpected end of input while reading {what}")))
+}
+fn next_f64<'a>(tokens: &mut impl Iterator<Item = &'a str>, what: &str) -> Result<f64, Error> {
+    next_token(tokens, what)?
+        .parse()
+        .map_err(|_| invalid_data(&format!("{what} is not a valid number")))
+}
+fn next_usize<'a>(tokens: &mut impl Iterator<Item = &'a str>, what: &str) -> Result<usize, Error> {
+    next_token(tokens, what)?
+        .parse()
+        .map_err(|_| invalid_data(&format!("{what} is not a valid integer")))
+}
+
+/// Returns the smallest gap between distinct, sorted values in `values`, or `None` if fewer than
+/// two distinct values are present (e.g. every point shares the same coordinate on this axis).
+fn min_positive_gap(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let mut sorted: Vec<f64> = values.collect();
+    sorted.sort_by(f64::total_cmp);
+    sorted.dedup();
+    sorted.windows(2).map(|w| w[1] - w[0]).fold(None, |min, gap| {
+        Some(min.map_or(gap, |min: f64| min.min(gap)))
+    })
+}
+
+/// Detects the format of `path` and opens it as an [`EsriASCIIReader`], normalizing Surfer ASCII
+/// grids and XYZ point files into the same [`EsriASCIIRasterHeader`] + row/column model used by
+/// native `.asc` files.
+///
+/// # Errors
+/// Returns an IO error if the file cannot be read or is malformed for its detected format.
+pub fn from_file_detect(path: &Path) -> Result<EsriASCIIReader<Cursor<Vec<u8>>>, Error> {
+    let mut text = String::new();
+    File::open(path)?.read_to_string(&mut text)?;
+    let first_token = text.split_whitespace().next().unwrap_or_default();
+    match GridFormat::detect(path, first_token) {
+        GridFormat::EsriAscii => EsriASCIIReader::from_file(Cursor::new(text.into_bytes())),
+        GridFormat::SurferAscii => from_surfer_str(&text),
+        GridFormat::Xyz => from_xyz_str(&text),
+    }
+}
+
+/// Parses a Surfer 6 text grid (`.grd`) and returns it through the standard `EsriASCIIReader`
+/// API.
+///
+/// # Errors
+/// Returns an IO error if the `DSAA` header is missing or malformed, or if fewer than
+/// `ncols * nrows` z-values are present.
+pub fn from_surfer_str(text: &str) -> Result<EsriASCIIReader<Cursor<Vec<u8>>>, Error> {
+    let mut tokens = text.split_whitespace();
+    let magic = next_token(&mut tokens, "DSAA magic")?;
+    if !magic.eq_ignore_ascii_case(SURFER_MAGIC) {
+        return Err(invalid_data("not a Surfer DSAA grid"));
+    }
+    let ncols = next_usize(&mut tokens, "nx")?;
+    let nrows = next_usize(&mut tokens, "ny")?;
+    let xlo = next_f64(&mut tokens, "xlo")?;
+    let xhi = next_f64(&mut tokens, "xhi")?;
+    let ylo = next_f64(&mut tokens, "ylo")?;
+    let _yhi = next_f64(&mut tokens, "yhi")?;
+    let _zlo = next_f64(&mut tokens, "zlo")?;
+    let _zhi = next_f64(&mut tokens, "zhi")?;
+
+    let cellsize = if ncols > 1 {
+        (xhi - xlo) / (ncols - 1) as f64
+    } else {
+        1.0
+    };
+    let header = EsriASCIIRasterHeader {
+        ncols,
+        nrows,
+        xllcorner: xlo,
+        yllcorner: ylo,
+        cellsize,
+        nodata_value: SURFER_NODATA_VALUE,
+    };
+
+    // Surfer stores z-values row-major starting at `ylo` (the bottom row), matching this crate's
+    // row-0-is-bottom convention directly.
+    let mut grid = Vec::with_capacity(ncols * nrows);
+    for _ in 0..ncols * nrows {
+        grid.push(next_f64(&mut tokens, "z value")?);
+    }
+
+    reader_from_grid(header, ncols, &grid)
+}
+
+/// Parses a whitespace-separated `x y z` point file, inferring `cellsize`, the lower-left
+/// origin, and `ncols`/`nrows` from the coordinate extents, and snapping each point to its
+/// nearest cell.
+///
+/// # Errors
+/// Returns an IO error if a row cannot be parsed as three numbers, or if the file has no points.
+pub fn from_xyz_str(text: &str) -> Result<EsriASCIIReader<Cursor<Vec<u8>>>, Error> {
+    let mut points = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let x = next_f64(&mut fields, "x")?;
+        let y = next_f64(&mut fields, "y")?;
+        let z = next_f64(&mut fields, "z")?;
+        if !x.is_finite() || !y.is_finite() {
+            return Err(invalid_data("XYZ coordinates must be finite"));
+        }
+        points.push((x, y, z));
+    }
+    if points.is_empty() {
+        return Err(invalid_data("XYZ file contains no points"));
+    }
+
+    // Grid spacing is inferred from whichever axis actually varies; a transect with points
+    // sharing every x (or every y) must not collapse the other axis down to a single row/column.
+    let x_gap = min_positive_gap(points.iter().map(|(x, _, _)| *x));
+    let y_gap = min_positive_gap(points.iter().map(|(_, y, _)| *y));
+    let cellsize = match (x_gap, y_gap) {
+        (Some(a), Some(b)) => a.min(b),
+        (Some(a), None) | (None, Some(a)) => a,
+        (None, None) => 1.0,
+    };
+
+    let min_x = points.iter().map(|(x, _, _)| *x).fold(f64::MAX, f64::min);
+    let max_x = points.iter().map(|(x, _, _)| *x).fold(f64::MIN, f64::max);
+    let min_y = points.iter().map(|(_, y, _)| *y).fold(f64::MAX, f64::min);
+    let max_y = points.iter().map(|(_, y, _)| *y).fold(f64::MIN, f64::max);
+
+    let ncols = ((max_x - min_x) / cellsize).round() as usize + 1;
+    let nrows = ((max_y - min_y) / cellsize).round() as usize + 1;
+    match ncols.checked_mul(nrows) {
+        Some(cells) if cells <= MAX_XYZ_GRID_CELLS => {}
+        _ => {
+            return Err(invalid_data(
+                "XYZ points imply a grid that is too large; check for near-duplicate \
+                 coordinates collapsing the cell size",
+            ))
+        }
+    }
+    let nodata_value = -9999.0;
+
+    let header = EsriASCIIRasterHeader {
+        ncols,
+        nrows,
+        xllcorner: min_x,
+        yllcorner: min_y,
+        cellsize,
+        nodata_value,
+    };
+
+    let mut grid = vec![nodata_value; ncols * nrows];
+    for (x, y, z) in points {
+        let col = ((x - min_x) / cellsize).round() as usize;
+        let row = ((y - min_y) / cellsize).round() as usize;
+        if row < nrows && col < ncols {
+            grid[row * ncols + col] = z;
+        }
+    }
+
+    reader_from_grid(header, ncols, &grid)
+}
+
+/// Serializes a row-major `grid` (row 0 = bottom) through [`EsriASCIIWriter`] into an in-memory
+/// `.asc` buffer, then reopens it as an [`EsriASCIIReader`] so the rest of the API (`get`,
+/// `get_interpolate`, the iterator) works unchanged regardless of the original source format.
+fn reader_from_grid(
+    header: EsriASCIIRasterHeader,
+    ncols: usize,
+    grid: &[f64],
+) -> Result<EsriASCIIReader<Cursor<Vec<u8>>>, Error> {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = EsriASCIIWriter::new(header, Cursor::new(&mut buffer));
+        writer.write_from_fn(|row, col| grid[row * ncols + col])?;
+    }
+    EsriASCIIReader::from_file(Cursor::new(buffer))
+}
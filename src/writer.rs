@@ -0,0 +1,133 @@
+use std::io::{BufWriter, Error, Write};
+
+use crate::header::EsriASCIIRasterHeader;
+
+/// Writes an ESRI ASCII raster (`.asc`) to any `Write` destination.
+///
+/// This is the write-side counterpart to [`EsriASCIIReader`](crate::ascii_file::EsriASCIIReader):
+/// given a header and a source of values it streams out the six header lines
+/// followed by the whitespace-separated rows, top-to-bottom.
+pub struct EsriASCIIWriter<W> {
+    pub header: EsriASCIIRasterHeader,
+    writer: BufWriter<W>,
+    header_written: bool,
+}
+impl<W: Write> EsriASCIIWriter<W> {
+    /// Create a new `EsriASCIIWriter` that will write a raster matching `header` to `writer`.
+    ///
+    /// Nothing is written until [`write_header`](Self::write_header) or one of the
+    /// `write_*` helpers is called.
+    pub fn new(header: EsriASCIIRasterHeader, writer: W) -> Self {
+        Self {
+            header,
+            writer: BufWriter::new(writer),
+            header_written: false,
+        }
+    }
+    /// Writes the six header lines (`ncols`, `nrows`, `xllcorner`, `yllcorner`, `cellsize`,
+    /// `NODATA_value`) if they have not already been written.
+    ///
+    /// # Errors
+    /// Returns an IO error if the underlying writer fails.
+    pub fn write_header(&mut self) -> Result<(), Error> {
+        if self.header_written {
+            return Ok(());
+        }
+        writeln!(self.writer, "ncols {}", self.header.ncols)?;
+        writeln!(self.writer, "nrows {}", self.header.nrows)?;
+        writeln!(self.writer, "xllcorner {}", self.header.xllcorner)?;
+        writeln!(self.writer, "yllcorner {}", self.header.yllcorner)?;
+        writeln!(self.writer, "cellsize {}", self.header.cellsize)?;
+        writeln!(self.writer, "NODATA_value {}", self.header.nodata_value)?;
+        self.header_written = true;
+        Ok(())
+    }
+    /// Writes the full grid by calling `value_at(row, col)` for every cell, top-to-bottom,
+    /// left-to-right, matching the iteration order of
+    /// [`EsriASCIIReader`'s `IntoIterator`](crate::ascii_file::EsriASCIIRasterIntoIterator).
+    ///
+    /// The header is written first if it has not been already.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use esri_ascii_grid::{header::EsriASCIIRasterHeader, writer::EsriASCIIWriter};
+    /// let header = EsriASCIIRasterHeader {
+    ///     ncols: 2,
+    ///     nrows: 2,
+    ///     xllcorner: 0.0,
+    ///     yllcorner: 0.0,
+    ///     cellsize: 1.0,
+    ///     nodata_value: -9999.0,
+    /// };
+    /// let mut out = Vec::new();
+    /// let mut writer = EsriASCIIWriter::new(header, &mut out);
+    /// writer.write_from_fn(|row, col| (row * 2 + col) as f64).unwrap();
+    /// drop(writer);
+    /// // Rows are written top-to-bottom, i.e. row 1 (the top row) before row 0.
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "ncols 2\nnrows 2\nxllcorner 0\nyllcorner 0\ncellsize 1\nNODATA_value -9999\n2 3\n0 1\n"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an IO error if the underlying writer fails.
+    pub fn write_from_fn<F>(&mut self, mut value_at: F) -> Result<(), Error>
+    where
+        F: FnMut(usize, usize) -> f64,
+    {
+        self.write_header()?;
+        for row in (0..self.header.nrows).rev() {
+            for col in 0..self.header.ncols {
+                if col > 0 {
+                    write!(self.writer, " ")?;
+                }
+                write!(self.writer, "{}", value_at(row, col))?;
+            }
+            writeln!(self.writer)?;
+        }
+        self.writer.flush()
+    }
+    /// Writes the full grid from an iterator of `(row, col, value)` triples.
+    ///
+    /// Cells not produced by the iterator are written as the header's `NODATA_value`.
+    /// Unlike [`write_from_fn`](Self::write_from_fn), the iterator may yield items in any
+    /// order; the whole grid is buffered in memory before being written out.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use esri_ascii_grid::{header::EsriASCIIRasterHeader, writer::EsriASCIIWriter};
+    /// let header = EsriASCIIRasterHeader {
+    ///     ncols: 2,
+    ///     nrows: 2,
+    ///     xllcorner: 0.0,
+    ///     yllcorner: 0.0,
+    ///     cellsize: 1.0,
+    ///     nodata_value: -9999.0,
+    /// };
+    /// let mut out = Vec::new();
+    /// let mut writer = EsriASCIIWriter::new(header, &mut out);
+    /// // (row 0, col 1) and (row 1, col 0) are never yielded, so they fall back to `NODATA_value`.
+    /// writer.write_from_iter([(0, 0, 1.0), (1, 1, 4.0)]).unwrap();
+    /// drop(writer);
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     "ncols 2\nnrows 2\nxllcorner 0\nyllcorner 0\ncellsize 1\nNODATA_value -9999\n-9999 4\n1 -9999\n"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an IO error if the underlying writer fails.
+    pub fn write_from_iter<I>(&mut self, values: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = (usize, usize, f64)>,
+    {
+        let mut grid = vec![self.header.nodata_value; self.header.nrows * self.header.ncols];
+        for (row, col, value) in values {
+            if row < self.header.nrows && col < self.header.ncols {
+                grid[row * self.header.ncols + col] = value;
+            }
+        }
+        self.write_from_fn(|row, col| grid[row * self.header.ncols + col])
+    }
+}